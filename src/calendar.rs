@@ -1,5 +1,7 @@
 use chrono::{Datelike, Local, NaiveDate, Weekday};
 use colored::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 
 // Configurable spacing between months (number of spaces)
 const SPACE_BETWEEN_MONTHS: usize = 3;
@@ -16,84 +18,318 @@ pub enum DisplayMode {
     WeekdaysOnly,
 }
 
+// How the calendar is rendered: colored fixed-width text for a terminal, or
+// a machine-readable dump for piping into other tools. `DisplayMode` still
+// controls the text renderer's layout; this is orthogonal to it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Tsv,
+}
+
+// A single row of a month grid, paired with the ISO-8601 week number of the
+// calendar week it represents (used by the `--week-numbers` gutter).
+struct GridRow {
+    week: u32,
+    cells: Vec<String>,
+}
+
+// One day's worth of machine-readable data, used by the `--format json`
+// and `--format tsv` output modes.
+#[derive(Serialize)]
+struct DayRecord {
+    year: i32,
+    month: u32,
+    day: u32,
+    weekday: &'static str,
+    is_weekend: bool,
+    is_today: bool,
+    iso_week: u32,
+}
+
+#[derive(Serialize)]
+struct MonthRecord {
+    year: i32,
+    month: u32,
+    weeks: Vec<Vec<DayRecord>>,
+}
+
+// Everything needed to construct a `Calendar`, bundled up so `new`/`with_today`
+// take one argument instead of growing a positional parameter per flag.
+pub struct CalendarConfig {
+    pub year: i32,
+    pub mode: DisplayMode,
+    pub week_start: Weekday,
+    pub week_numbers: bool,
+    pub per_row: usize,
+    pub format: OutputFormat,
+    pub events: HashMap<NaiveDate, Vec<String>>,
+}
+
 pub struct Calendar {
     year: i32,
     mode: DisplayMode,
     today: Option<NaiveDate>,
+    week_start: Weekday,
+    week_numbers: bool,
+    per_row: usize,
+    format: OutputFormat,
+    events: HashMap<NaiveDate, Vec<String>>,
 }
 
 impl Calendar {
-    pub fn new(year: i32, mode: DisplayMode) -> Self {
+    pub fn new(config: CalendarConfig) -> Self {
         let today = Local::now().date_naive();
-        Self::with_today(year, mode, today)
+        Self::with_today(config, today)
     }
-    
-    pub fn with_today(year: i32, mode: DisplayMode, today: NaiveDate) -> Self {
-        Self { year, mode, today: Some(today) }
+
+    pub fn with_today(config: CalendarConfig, today: NaiveDate) -> Self {
+        Self {
+            year: config.year,
+            mode: config.mode,
+            today: Some(today),
+            week_start: config.week_start,
+            week_numbers: config.week_numbers,
+            per_row: config.per_row,
+            format: config.format,
+            events: config.events,
+        }
     }
-    
+
     #[allow(dead_code)] // Used in tests
     pub fn today(&self) -> Option<NaiveDate> {
         self.today
     }
     
     fn month_width(&self) -> usize {
-        match self.mode {
+        let base = match self.mode {
             DisplayMode::Full => 20,
             DisplayMode::WeekdaysOnly => 14,
+        };
+        // "NN " gutter for the ISO week-number column
+        if self.week_numbers { base + 3 } else { base }
+    }
+
+    fn day_header(&self) -> String {
+        let days = self.ordered_days()
+            .iter()
+            .map(Self::weekday_abbrev)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if self.week_numbers {
+            format!("   {}", days)
+        } else {
+            days
         }
     }
-    
-    fn day_header(&self) -> &str {
+
+    // The sequence of weekdays across a grid row, starting at `week_start`.
+    // In `WeekdaysOnly` mode the weekend entries are dropped but the
+    // relative order around the anchor is preserved.
+    fn ordered_days(&self) -> Vec<Weekday> {
+        let mut days = Vec::with_capacity(7);
+        let mut day = self.week_start;
+        for _ in 0..7 {
+            days.push(day);
+            day = day.succ();
+        }
+
         match self.mode {
-            DisplayMode::Full => "Su Mo Tu We Th Fr Sa",
-            DisplayMode::WeekdaysOnly => "Mo Tu We Th Fr",
+            DisplayMode::Full => days,
+            DisplayMode::WeekdaysOnly => days.into_iter().filter(|d| !self.is_weekend(*d)).collect(),
+        }
+    }
+
+    fn weekday_abbrev(weekday: &Weekday) -> &'static str {
+        match weekday {
+            Weekday::Sun => "Su",
+            Weekday::Mon => "Mo",
+            Weekday::Tue => "Tu",
+            Weekday::Wed => "We",
+            Weekday::Thu => "Th",
+            Weekday::Fri => "Fr",
+            Weekday::Sat => "Sa",
         }
     }
 
     pub fn display(&self) {
-        print!("{}", self.format_year());
+        match self.format {
+            OutputFormat::Text => print!("{}", self.format_year()),
+            OutputFormat::Json => println!("{}", self.format_year_json()),
+            OutputFormat::Tsv => print!("{}", self.format_year_tsv()),
+        }
     }
-    
+
     pub fn format_year(&self) -> String {
         let mut output = String::new();
-        
-        // Calculate total width based on display mode
+
+        // Calculate total width based on display mode and months-per-row
         let month_width = self.month_width();
-        let total_width = month_width * 3 + SPACE_BETWEEN_MONTHS * 2;
-        
+        let per_row = self.per_row;
+        let total_width = month_width * per_row + SPACE_BETWEEN_MONTHS * (per_row - 1);
+
         output.push_str(&format!("{:^width$}\n", self.year, width = total_width));
         output.push('\n');
 
-        // Display calendar in quarters (3 months per row)
-        for quarter in 0..4 {
-            output.push_str(&self.format_quarter(quarter));
-            if quarter < 3 {
+        // Display all 12 months, `per_row` at a time
+        let months: Vec<(i32, i32)> = (1..=12).map(|month| (self.year, month)).collect();
+        let rows: Vec<&[(i32, i32)]> = months.chunks(per_row).collect();
+        for (i, chunk) in rows.iter().enumerate() {
+            output.push_str(&self.format_month_row(chunk, per_row));
+            if i < rows.len() - 1 {
                 output.push('\n');
             }
         }
-        
+
+        output.push_str(&self.format_events_legend(&months));
         output
     }
 
     pub fn display_months(&self, current_month: i32, months_before: i32, months_after: i32) {
-        print!("{}", self.format_months(current_month, months_before, months_after));
+        match self.format {
+            OutputFormat::Text => print!("{}", self.format_months(current_month, months_before, months_after)),
+            OutputFormat::Json => println!("{}", self.format_months_json(current_month, months_before, months_after)),
+            OutputFormat::Tsv => print!("{}", self.format_months_tsv(current_month, months_before, months_after)),
+        }
     }
-    
+
     pub fn format_months(&self, current_month: i32, months_before: i32, months_after: i32) -> String {
         let mut output = String::new();
-        
+
         // Calculate which months to display
         let months_to_display = self.calculate_months_to_display(current_month, months_before, months_after);
-        
-        // Format months in groups of up to 3 per row
-        for chunk in months_to_display.chunks(3) {
-            output.push_str(&self.format_month_row(chunk));
-            if chunk.len() == 3 && months_to_display.len() > 3 {
+
+        // Format months in groups of up to `per_row` per row
+        let rows: Vec<&[(i32, i32)]> = months_to_display.chunks(self.per_row).collect();
+        for (i, chunk) in rows.iter().enumerate() {
+            output.push_str(&self.format_month_row(chunk, self.per_row));
+            if i < rows.len() - 1 {
                 output.push('\n');
             }
         }
-        
+
+        output.push_str(&self.format_events_legend(&months_to_display));
+        output
+    }
+
+    pub fn format_year_json(&self) -> String {
+        let months: Vec<(i32, i32)> = (1..=12).map(|month| (self.year, month)).collect();
+        serde_json::to_string_pretty(&self.structured_months(&months)).unwrap_or_default()
+    }
+
+    pub fn format_months_json(&self, current_month: i32, months_before: i32, months_after: i32) -> String {
+        let months = self.calculate_months_to_display(current_month, months_before, months_after);
+        serde_json::to_string_pretty(&self.structured_months(&months)).unwrap_or_default()
+    }
+
+    pub fn format_year_tsv(&self) -> String {
+        let months: Vec<(i32, i32)> = (1..=12).map(|month| (self.year, month)).collect();
+        self.format_months_as_tsv(&months)
+    }
+
+    pub fn format_months_tsv(&self, current_month: i32, months_before: i32, months_after: i32) -> String {
+        let months = self.calculate_months_to_display(current_month, months_before, months_after);
+        self.format_months_as_tsv(&months)
+    }
+
+    // Per-month week/day records behind `--format json`, independent of
+    // `DisplayMode` and carrying no ANSI escapes.
+    fn structured_months(&self, months: &[(i32, i32)]) -> Vec<MonthRecord> {
+        months
+            .iter()
+            .map(|(year, month)| MonthRecord {
+                year: *year,
+                month: *month as u32,
+                weeks: self.weeks_for_month(*year, *month as usize),
+            })
+            .collect()
+    }
+
+    fn format_months_as_tsv(&self, months: &[(i32, i32)]) -> String {
+        let mut output = String::from("year\tmonth\tday\tweekday\tis_weekend\tis_today\tiso_week\n");
+        for month_record in self.structured_months(months) {
+            for week in &month_record.weeks {
+                for day in week {
+                    output.push_str(&format!(
+                        "{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                        day.year, day.month, day.day, day.weekday, day.is_weekend, day.is_today, day.iso_week
+                    ));
+                }
+            }
+        }
+        output
+    }
+
+    // Groups a month's days into weeks that begin on `week_start`, keeping
+    // weekends regardless of `DisplayMode`. The first and last weeks hold
+    // only the month's own days, so they can have fewer than 7 entries.
+    fn weeks_for_month(&self, year: i32, month: usize) -> Vec<Vec<DayRecord>> {
+        let days_in_month = self.days_in_month_for_year(year, month);
+        let first_day = NaiveDate::from_ymd_opt(year, month as u32, 1).unwrap();
+        let mut col = self.weekday_offset_from_start(first_day.weekday());
+
+        let mut weeks = Vec::new();
+        let mut current_week = Vec::new();
+        for day in 1..=days_in_month {
+            current_week.push(self.day_record(year, month as u32, day));
+            col += 1;
+            if col == 7 {
+                weeks.push(std::mem::take(&mut current_week));
+                col = 0;
+            }
+        }
+        if !current_week.is_empty() {
+            weeks.push(current_week);
+        }
+
+        weeks
+    }
+
+    fn day_record(&self, year: i32, month: u32, day: u32) -> DayRecord {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let weekday = date.weekday();
+        DayRecord {
+            year,
+            month,
+            day,
+            weekday: Self::weekday_name(weekday),
+            is_weekend: self.is_weekend(weekday),
+            is_today: self.today == Some(date),
+            iso_week: date.iso_week().week(),
+        }
+    }
+
+    fn weekday_name(weekday: Weekday) -> &'static str {
+        match weekday {
+            Weekday::Sun => "Sunday",
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+        }
+    }
+
+    // A labeled list of `--events` dates falling within `months`, printed
+    // beneath the grid. Empty when there are no events to show, so callers
+    // can unconditionally append the result.
+    fn format_events_legend(&self, months: &[(i32, i32)]) -> String {
+        let mut dates: Vec<&NaiveDate> = self
+            .events
+            .keys()
+            .filter(|date| months.iter().any(|(year, month)| date.year() == *year && date.month() == *month as u32))
+            .collect();
+        if dates.is_empty() {
+            return String::new();
+        }
+        dates.sort();
+
+        let mut output = String::from("\nEvents:\n");
+        for date in dates {
+            output.push_str(&format!("  {}  {}\n", date.format("%Y-%m-%d"), self.events[date].join(", ")));
+        }
         output
     }
 
@@ -132,14 +368,18 @@ impl Calendar {
         (new_year, new_month)
     }
 
-    fn format_month_row(&self, months: &[(i32, i32)]) -> String {
+    // Renders one row of up to `months.len()` month grids side by side. If
+    // `months` is shorter than `per_row` (a ragged final row), blank cells
+    // pad it out to `per_row` so the row stays `per_row` months wide.
+    fn format_month_row(&self, months: &[(i32, i32)], per_row: usize) -> String {
         let mut output = String::new();
         let month_width = self.month_width();
-        
+        let blank_cell = " ".repeat(month_width);
+
         let current_year = Local::now().year();
-        
+
         // Month headers
-        let month_header = months
+        let mut month_header_cells: Vec<String> = months
             .iter()
             .map(|(year, month)| {
                 let month_name = MONTH_NAMES[(*month - 1) as usize];
@@ -152,17 +392,17 @@ impl Calendar {
                     format!("{:^width$}", format!("{} {}", month_name, two_digit_year), width = month_width)
                 }
             })
-            .collect::<Vec<_>>()
-            .join(&" ".repeat(SPACE_BETWEEN_MONTHS));
-        output.push_str(&format!("{}\n", month_header));
+            .collect();
+        month_header_cells.resize(per_row, blank_cell.clone());
+        output.push_str(&format!("{}\n", month_header_cells.join(&" ".repeat(SPACE_BETWEEN_MONTHS))));
 
         // Day headers
-        let day_header_str = self.day_header();
-        let headers = vec![day_header_str; months.len()].join(&" ".repeat(SPACE_BETWEEN_MONTHS));
-        output.push_str(&format!("{}\n", headers));
+        let mut day_header_cells: Vec<String> = vec![self.day_header(); months.len()];
+        day_header_cells.resize(per_row, blank_cell.clone());
+        output.push_str(&format!("{}\n", day_header_cells.join(&" ".repeat(SPACE_BETWEEN_MONTHS))));
 
         // Generate month grids
-        let month_grids: Vec<Vec<Vec<String>>> = months
+        let month_grids: Vec<Vec<GridRow>> = months
             .iter()
             .map(|(year, month)| self.generate_month_grid_for_year_month(*year, *month as usize))
             .collect();
@@ -170,72 +410,101 @@ impl Calendar {
         // Generate rows
         let max_rows = month_grids.iter().map(|grid| grid.len()).max().unwrap_or(0);
         for row in 0..max_rows {
-            let row_parts: Vec<String> = month_grids
+            let mut row_parts: Vec<String> = month_grids
                 .iter()
                 .map(|grid| {
                     if row < grid.len() {
                         self.format_grid_row(&grid[row])
                     } else {
-                        " ".repeat(month_width)
+                        blank_cell.clone()
                     }
                 })
                 .collect();
+            row_parts.resize(per_row, blank_cell.clone());
             output.push_str(&format!("{}\n", row_parts.join(&" ".repeat(SPACE_BETWEEN_MONTHS))));
         }
-        
+
         output
     }
 
-    fn generate_month_grid_for_year_month(&self, year: i32, month: usize) -> Vec<Vec<String>> {
+    fn generate_month_grid_for_year_month(&self, year: i32, month: usize) -> Vec<GridRow> {
         let mut grid = Vec::new();
-        
+
         let first_day = NaiveDate::from_ymd_opt(year, month as u32, 1).unwrap();
         let days_in_month = self.days_in_month_for_year(year, month);
-        
+
         let cols = match self.mode {
             DisplayMode::Full => 7,
             DisplayMode::WeekdaysOnly => 5,
         };
-        
+
         let mut current_row = vec!["  ".to_string(); cols];
+        let mut current_row_days: Vec<u32> = Vec::with_capacity(cols);
         let mut current_day = 1;
-        
+
         // Calculate starting column
         let start_col = self.get_start_column(first_day);
         let mut col = start_col;
-        
+
         // Fill the grid
         while current_day <= days_in_month {
             if col >= cols {
                 // Start new row
-                grid.push(current_row);
+                grid.push(self.finish_grid_row(year, month, current_row, &current_row_days));
                 current_row = vec!["  ".to_string(); cols];
+                current_row_days = Vec::with_capacity(cols);
                 col = 0;
             }
-            
+
             // Skip weekends in weekdays-only mode
             let weekday = self.get_weekday_for_day_and_year(year, month, current_day);
             if matches!(self.mode, DisplayMode::WeekdaysOnly) && self.is_weekend(weekday) {
                 current_day += 1;
                 continue;
             }
-            
+
             // Format the day
             let day_str = self.format_day_for_year_month(year, month, current_day, weekday);
             current_row[col] = day_str;
-            
+            current_row_days.push(current_day);
+
             current_day += 1;
             col += 1;
         }
-        
+
         // Add the last row if it has content
         if current_row.iter().any(|cell| cell != "  ") {
-            grid.push(current_row);
+            grid.push(self.finish_grid_row(year, month, current_row, &current_row_days));
         }
-        
+
         grid
     }
 
+    // Pairs a finished grid row with the ISO-8601 week number of the Thursday
+    // of the calendar week it represents. A row's visible days can straddle
+    // two ISO weeks whenever `week_start` isn't Monday (e.g. the lone Sunday
+    // that leads a Sunday-anchored row belongs to the *previous* ISO week),
+    // so the week is derived from whichever underlying Mon-Sun week most of
+    // the row's real days fall into, rather than from the first displayed day.
+    fn finish_grid_row(&self, year: i32, month: usize, cells: Vec<String>, days: &[u32]) -> GridRow {
+        GridRow { week: self.iso_week_for_days(year, month, days), cells }
+    }
+
+    fn iso_week_for_days(&self, year: i32, month: usize, days: &[u32]) -> u32 {
+        let mut thursdays: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        for &day in days {
+            let date = NaiveDate::from_ymd_opt(year, month as u32, day).unwrap();
+            let monday_offset = date.weekday().num_days_from_monday() as i64;
+            let thursday = date + chrono::Duration::days(3 - monday_offset);
+            *thursdays.entry(thursday).or_insert(0) += 1;
+        }
+        thursdays
+            .into_iter()
+            .max_by_key(|&(date, count)| (count, date))
+            .map(|(date, _)| date.iso_week().week())
+            .unwrap_or(0)
+    }
+
     fn get_weekday_for_day_and_year(&self, year: i32, month: usize, day: u32) -> Weekday {
         NaiveDate::from_ymd_opt(year, month as u32, day)
             .unwrap()
@@ -251,7 +520,12 @@ impl Calendar {
                 return format!("{}", day_str.black().on_white());
             }
         }
-        
+
+        // Check if this is an annotated `--events` date (outranks weekend dimming)
+        if self.events.contains_key(&NaiveDate::from_ymd_opt(year, month as u32, day).unwrap()) {
+            return format!("{}", day_str.bright_red());
+        }
+
         // Check if this is a weekend (only color in full mode)
         if matches!(self.mode, DisplayMode::Full) && self.is_weekend(weekday) {
             format!("{}", day_str.bright_black())
@@ -279,80 +553,42 @@ impl Calendar {
         (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
     }
 
-    fn format_quarter(&self, quarter: usize) -> String {
-        let mut output = String::new();
-        let months: Vec<usize> = (0..3).map(|i| quarter * 3 + i + 1).collect();
-        
-        // Month headers - adjust width based on mode
-        let month_width = self.month_width();
-        
-        let month_header = months
-            .iter()
-            .map(|&m| format!("{:^width$}", MONTH_NAMES[m - 1], width = month_width))
-            .collect::<Vec<_>>()
-            .join(&" ".repeat(SPACE_BETWEEN_MONTHS));
-        output.push_str(&format!("{}\n", month_header));
-
-        // Day headers
-        let day_header_str = self.day_header();
-        let headers = vec![day_header_str; 3].join(&" ".repeat(SPACE_BETWEEN_MONTHS));
-        output.push_str(&format!("{}\n", headers));
-
-        // Generate month grids
-        let month_grids: Vec<Vec<Vec<String>>> = months
-            .iter()
-            .map(|&month| self.generate_month_grid(month))
-            .collect();
-
-        // Generate rows
-        let max_rows = month_grids.iter().map(|grid| grid.len()).max().unwrap_or(0);
-        for row in 0..max_rows {
-            let row_parts: Vec<String> = month_grids
-                .iter()
-                .map(|grid| {
-                    if row < grid.len() {
-                        self.format_grid_row(&grid[row])
-                    } else {
-                        " ".repeat(month_width) // Use dynamic width
-                    }
-                })
-                .collect();
-            output.push_str(&format!("{}\n", row_parts.join(&" ".repeat(SPACE_BETWEEN_MONTHS))));
-        }
-        
-        output
-    }
-
-    fn generate_month_grid(&self, month: usize) -> Vec<Vec<String>> {
-        // Delegate to the more flexible method using self.year
-        self.generate_month_grid_for_year_month(self.year, month)
-    }
-    
     fn get_start_column(&self, first_day: NaiveDate) -> usize {
         match self.mode {
-            DisplayMode::Full => first_day.weekday().num_days_from_sunday() as usize,
+            DisplayMode::Full => self.weekday_offset_from_start(first_day.weekday()),
             DisplayMode::WeekdaysOnly => {
-                let weekday = first_day.weekday();
-                if weekday == Weekday::Sat || weekday == Weekday::Sun {
-                    // If month starts on weekend, start at column 0 (Monday)
-                    0
-                } else {
-                    // Convert to weekday index: Mon=0, Tue=1, ..., Fri=4
-                    (weekday.num_days_from_monday() as usize).min(4)
+                // If the month starts on a weekend there's no matching column
+                // for that day; walk forward to the month's first actual
+                // weekday and use its position in the rotated header instead.
+                let mut weekday = first_day.weekday();
+                while self.is_weekend(weekday) {
+                    weekday = weekday.succ();
                 }
+                self.ordered_days().iter().position(|&d| d == weekday).unwrap_or(0)
             }
         }
     }
-    
+
+    // How many columns `weekday` sits past `week_start`, for a 7-wide row.
+    fn weekday_offset_from_start(&self, weekday: Weekday) -> usize {
+        (weekday.num_days_from_monday() + 7 - self.week_start.num_days_from_monday()) as usize % 7
+    }
+
     fn is_weekend(&self, weekday: Weekday) -> bool {
         weekday == Weekday::Sat || weekday == Weekday::Sun
     }
     
-    fn format_grid_row(&self, row: &[String]) -> String {
-        let row_str = row.join(" ");
-        match self.mode {
+    fn format_grid_row(&self, row: &GridRow) -> String {
+        let row_str = row.cells.join(" ");
+        let days = match self.mode {
             DisplayMode::Full => format!("{:20}", row_str),
             DisplayMode::WeekdaysOnly => format!("{:14}", row_str), // Reduced width, no extra padding
+        };
+
+        if self.week_numbers {
+            format!("{} {}", format!("{:2}", row.week).dimmed(), days)
+        } else {
+            days
         }
     }
 
@@ -367,6 +603,28 @@ impl Calendar {
     }
 }
 
+// Parses a `--events` file: one `YYYY-MM-DD[,label]` per line. Blank lines
+// and lines starting with `#` are skipped; a line without a label falls
+// back to "Event" so it still renders something in the legend.
+pub fn parse_events(contents: &str) -> Result<HashMap<NaiveDate, Vec<String>>, String> {
+    let mut events: HashMap<NaiveDate, Vec<String>> = HashMap::new();
+    for (line_no, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.splitn(2, ',');
+        let date_str = parts.next().unwrap().trim();
+        let label = parts.next().map(str::trim).filter(|s| !s.is_empty()).unwrap_or("Event");
+
+        let date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+            .map_err(|_| format!("line {}: invalid date {date_str:?} (expected YYYY-MM-DD)", line_no + 1))?;
+        events.entry(date).or_default().push(label.to_string());
+    }
+    Ok(events)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,27 +632,27 @@ mod tests {
 
     #[test]
     fn test_leap_year_calculation() {
-        let cal_2024 = Calendar::new(2024, DisplayMode::Full);
+        let cal_2024 = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         assert!(cal_2024.is_leap_year());
         
-        let cal_2023 = Calendar::new(2023, DisplayMode::Full);
+        let cal_2023 = Calendar::new(CalendarConfig { year: 2023, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         assert!(!cal_2023.is_leap_year());
         
-        let cal_1900 = Calendar::new(1900, DisplayMode::Full);
+        let cal_1900 = Calendar::new(CalendarConfig { year: 1900, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         assert!(!cal_1900.is_leap_year());
         
-        let cal_2000 = Calendar::new(2000, DisplayMode::Full);
+        let cal_2000 = Calendar::new(CalendarConfig { year: 2000, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         assert!(cal_2000.is_leap_year());
     }
 
     #[test]
     fn test_days_in_month() {
-        let cal = Calendar::new(2024, DisplayMode::Full);
+        let cal = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         assert_eq!(cal.days_in_month(2), 29); // Leap year February
         assert_eq!(cal.days_in_month(4), 30); // April
         assert_eq!(cal.days_in_month(1), 31); // January
         
-        let cal_non_leap = Calendar::new(2023, DisplayMode::Full);
+        let cal_non_leap = Calendar::new(CalendarConfig { year: 2023, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         assert_eq!(cal_non_leap.days_in_month(2), 28); // Non-leap year February
     }
 
@@ -404,7 +662,7 @@ mod tests {
     fn test_calendar_with_specific_today_date() {
         // FIXED: Now we can inject a specific "today" date for testing
         let specific_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
-        let cal = Calendar::with_today(2024, DisplayMode::Full, specific_date);
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() }, specific_date);
         
         // Test that the injected date is used
         assert_eq!(cal.today(), Some(specific_date));
@@ -419,7 +677,7 @@ mod tests {
     fn test_generate_month_output_as_string() {
         // FIXED: Now we can test formatted output directly
         let specific_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
-        let cal = Calendar::with_today(2024, DisplayMode::Full, specific_date);
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() }, specific_date);
         
         // Test full year formatting
         let year_output = cal.format_year();
@@ -435,7 +693,7 @@ mod tests {
         assert!(months_output.contains("April"));
         
         // Test weekdays-only mode
-        let cal_weekdays = Calendar::with_today(2024, DisplayMode::WeekdaysOnly, specific_date);
+        let cal_weekdays = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::WeekdaysOnly, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() }, specific_date);
         let weekdays_output = cal_weekdays.format_year();
         assert!(weekdays_output.contains("Mo Tu We Th Fr"));
         assert!(!weekdays_output.contains("Su"));
@@ -444,8 +702,8 @@ mod tests {
     
     #[test]
     fn test_formatting_methods() {
-        let cal_full = Calendar::new(2024, DisplayMode::Full);
-        let cal_weekdays = Calendar::new(2024, DisplayMode::WeekdaysOnly);
+        let cal_full = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
+        let cal_weekdays = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::WeekdaysOnly, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         
         // Test month width calculation
         assert_eq!(cal_full.month_width(), 20);
@@ -459,10 +717,180 @@ mod tests {
         assert_eq!(MONTH_NAMES[0], "January");
         assert_eq!(MONTH_NAMES[11], "December");
     }
-    
+
+    #[test]
+    fn test_week_start() {
+        let cal_monday = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Mon, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
+        assert_eq!(cal_monday.day_header(), "Mo Tu We Th Fr Sa Su");
+
+        let cal_wed_weekdays = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::WeekdaysOnly, week_start: Weekday::Wed, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
+        assert_eq!(cal_wed_weekdays.day_header(), "We Th Fr Mo Tu");
+
+        // March 1, 2024 is a Friday
+        let first_day = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(cal_monday.get_start_column(first_day), 4);
+
+        let cal_sunday = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
+        assert_eq!(cal_sunday.get_start_column(first_day), 5);
+    }
+
+    #[test]
+    fn test_week_start_weekdays_only_with_weekend_leading_month() {
+        // June 1, 2024 is a Saturday, so the first real (non-weekend) day of
+        // the month is Monday the 3rd.
+        let cal = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::WeekdaysOnly, week_start: Weekday::Wed, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
+        assert_eq!(cal.day_header(), "We Th Fr Mo Tu");
+
+        let first_day = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        // Monday sits at index 3 in the "We Th Fr Mo Tu" header, not column 0.
+        assert_eq!(cal.get_start_column(first_day), 3);
+
+        let grid = cal.generate_month_grid_for_year_month(2024, 6);
+        assert_eq!(grid[0].cells, vec!["  ", "  ", "  ", " 3", " 4"]);
+        assert_eq!(grid[1].cells, vec![" 5", " 6", " 7", "10", "11"]);
+
+        // September 1, 2024 is a Sunday; same bug, different weekend day.
+        let first_day = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        assert_eq!(cal.get_start_column(first_day), 3);
+    }
+
+    #[test]
+    fn test_week_numbers() {
+        let specific_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: true, per_row: 3, format: OutputFormat::Text, events: HashMap::new() }, specific_date);
+
+        assert_eq!(cal.month_width(), 23);
+        assert_eq!(cal.day_header(), "   Su Mo Tu We Th Fr Sa");
+
+        // January 1, 2024 falls in ISO week 1 of 2024
+        let grid = cal.generate_month_grid_for_year_month(2024, 1);
+        assert_eq!(grid[0].week, 1);
+
+        // December 31, 2024 falls in ISO week 1 of 2025
+        let grid = cal.generate_month_grid_for_year_month(2024, 12);
+        assert_eq!(grid.last().unwrap().week, 1);
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let contents = "\
+            # holidays\n\
+            2024-01-01,New Year's Day\n\
+            \n\
+            2024-07-04\n\
+        ";
+        let events = parse_events(contents).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[&NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()],
+            vec!["New Year's Day".to_string()]
+        );
+        assert_eq!(
+            events[&NaiveDate::from_ymd_opt(2024, 7, 4).unwrap()],
+            vec!["Event".to_string()]
+        );
+
+        assert!(parse_events("not-a-date").is_err());
+    }
+
+    #[test]
+    fn test_events_highlighting() {
+        // `colored` auto-disables escapes when stdout isn't a TTY (always
+        // true under `cargo test`), so force it on to actually exercise the
+        // rendered-string branch below.
+        colored::control::set_override(true);
+
+        let specific_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let mut events = HashMap::new();
+        events.insert(NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(), vec!["Deadline".to_string()]);
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events }, specific_date);
+
+        // The annotated day renders distinctly from a plain weekday.
+        let annotated = cal.format_day_for_year_month(2024, 1, 10, chrono::Weekday::Wed);
+        assert!(annotated.contains("10"));
+        assert_ne!(annotated, "10");
+
+        colored::control::unset_override();
+
+        // The legend lists only events within the displayed months.
+        let legend = cal.format_events_legend(&[(2024, 1)]);
+        assert!(legend.contains("2024-01-10"));
+        assert!(legend.contains("Deadline"));
+        assert!(cal.format_events_legend(&[(2024, 2)]).is_empty());
+    }
+
+    #[test]
+    fn test_structured_output() {
+        let specific_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Mon, week_numbers: false, per_row: 3, format: OutputFormat::Json, events: HashMap::new() }, specific_date);
+
+        // January 1, 2024 is a Monday, so the first week is a full 7 days
+        // starting in ISO week 1.
+        let months = cal.structured_months(&[(2024, 1)]);
+        assert_eq!(months.len(), 1);
+        let jan = &months[0];
+        assert_eq!(jan.year, 2024);
+        assert_eq!(jan.month, 1);
+        assert_eq!(jan.weeks[0].len(), 7);
+        assert_eq!(jan.weeks[0][0].day, 1);
+        assert_eq!(jan.weeks[0][0].weekday, "Monday");
+        assert_eq!(jan.weeks[0][0].iso_week, 1);
+        assert!(!jan.weeks[0][0].is_weekend);
+
+        // March 15, 2024 is the injected "today".
+        let today_record = day_record_for(&cal, 2024, 3, 15);
+        assert!(today_record.is_today);
+
+        // Saturdays/Sundays are flagged regardless of `week_start`.
+        let saturday = jan.weeks[0]
+            .iter()
+            .find(|d| d.weekday == "Saturday")
+            .unwrap();
+        assert!(saturday.is_weekend);
+
+        // No ANSI escapes leak into the JSON/TSV branches.
+        let json = cal.format_year_json();
+        assert!(!json.contains("\u{1b}["));
+        let tsv = cal.format_year_tsv();
+        assert!(!tsv.contains("\u{1b}["));
+        assert!(tsv.starts_with("year\tmonth\tday\tweekday\tis_weekend\tis_today\tiso_week\n"));
+    }
+
+    fn day_record_for(cal: &Calendar, year: i32, month: u32, day: u32) -> DayRecord {
+        cal.day_record(year, month, day)
+    }
+
+    #[test]
+    fn test_custom_per_row() {
+        let specific_date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 4, format: OutputFormat::Text, events: HashMap::new() }, specific_date);
+
+        // Every row should hold 4 month headers, so 12 months make 3 rows.
+        let year_output = cal.format_year();
+        let month_header_lines: Vec<&str> = year_output
+            .lines()
+            .filter(|line| MONTH_NAMES.iter().any(|name| line.contains(name)))
+            .collect();
+        assert_eq!(month_header_lines.len(), 3);
+        assert!(month_header_lines[0].contains("January"));
+        assert!(month_header_lines[0].contains("April"));
+        assert!(!month_header_lines[0].contains("May"));
+
+        // A ragged final row (5 months into rows of 4) still pads out to the
+        // full row width instead of leaving a short line.
+        let months_output = cal.format_months(1, 0, 4); // Jan..May
+        let header_lines: Vec<&str> = months_output
+            .lines()
+            .filter(|line| MONTH_NAMES.iter().any(|name| line.contains(name)))
+            .collect();
+        let row_width = cal.month_width() * 4 + SPACE_BETWEEN_MONTHS * 3;
+        assert_eq!(header_lines[0].len(), row_width);
+        assert_eq!(header_lines[1].len(), row_width);
+    }
+
     #[test]
     fn test_month_calculations() {
-        let cal = Calendar::new(2024, DisplayMode::Full);
+        let cal = Calendar::new(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() });
         
         // Test add_months logic
         assert_eq!(cal.add_months(2024, 12, 1), (2025, 1)); // Year boundary
@@ -476,7 +904,7 @@ mod tests {
     #[test]
     fn test_edge_cases() {
         let specific_date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(); // Leap day
-        let cal = Calendar::with_today(2024, DisplayMode::Full, specific_date);
+        let cal = Calendar::with_today(CalendarConfig { year: 2024, mode: DisplayMode::Full, week_start: Weekday::Sun, week_numbers: false, per_row: 3, format: OutputFormat::Text, events: HashMap::new() }, specific_date);
         
         // Test leap year February
         assert_eq!(cal.days_in_month_for_year(2024, 2), 29);
@@ -1,8 +1,9 @@
 use clap::Parser;
-use chrono::{Datelike, Local};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+use std::collections::HashMap;
 
 mod calendar;
-use calendar::{Calendar, DisplayMode};
+use calendar::{Calendar, CalendarConfig, DisplayMode, OutputFormat};
 
 #[derive(Parser)]
 #[command(
@@ -19,10 +20,57 @@ struct Args {
     #[arg(short = 'm', long = "months", num_args = 0..=2)]
     months: Option<Vec<i32>>,
 
+    /// First day of the week, e.g. "sunday" or "monday" (defaults to sunday)
+    #[arg(short = 's', long = "week-start", default_value = "sunday", value_parser = parse_weekday)]
+    week_start: Weekday,
+
+    /// Show an ISO-8601 week-number column to the left of each month
+    #[arg(short = 'n', long = "week-numbers")]
+    week_numbers: bool,
+
+    /// Number of months to display per row (defaults to 3)
+    #[arg(short = 'c', long = "columns", default_value_t = 3, value_parser = clap::value_parser!(u16).range(1..))]
+    columns: u16,
+
+    /// Output format: "text" (colored terminal grid), "json", or "tsv"
+    #[arg(short = 'f', long = "format", default_value = "text", value_parser = parse_format)]
+    format: OutputFormat,
+
+    /// Highlight dates loaded from a file of `YYYY-MM-DD[,label]` lines (holidays, deadlines, etc.)
+    #[arg(long = "events", value_parser = parse_events_file)]
+    events: Option<HashMap<NaiveDate, Vec<String>>>,
+
     /// Year to display (defaults to current year)
     year: Option<i32>,
 }
 
+fn parse_weekday(s: &str) -> Result<Weekday, String> {
+    match s.to_lowercase().as_str() {
+        "sunday" | "sun" => Ok(Weekday::Sun),
+        "monday" | "mon" => Ok(Weekday::Mon),
+        "tuesday" | "tue" => Ok(Weekday::Tue),
+        "wednesday" | "wed" => Ok(Weekday::Wed),
+        "thursday" | "thu" => Ok(Weekday::Thu),
+        "friday" | "fri" => Ok(Weekday::Fri),
+        "saturday" | "sat" => Ok(Weekday::Sat),
+        other => Err(format!("invalid weekday name: {other}")),
+    }
+}
+
+fn parse_format(s: &str) -> Result<OutputFormat, String> {
+    match s.to_lowercase().as_str() {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        "tsv" => Ok(OutputFormat::Tsv),
+        other => Err(format!("invalid format: {other} (expected text, json, or tsv)")),
+    }
+}
+
+fn parse_events_file(path: &str) -> Result<HashMap<NaiveDate, Vec<String>>, String> {
+    let contents = std::fs::read_to_string(path).map_err(|err| format!("could not read events file {path}: {err}"))?;
+    calendar::parse_events(&contents)
+}
+
 fn main() {
     let args = Args::parse();
     
@@ -31,6 +79,7 @@ fn main() {
     } else {
         DisplayMode::Full
     };
+    let events = args.events.unwrap_or_default();
 
     if let Some(months_args) = args.months {
         // Month display mode - hide year
@@ -45,12 +94,28 @@ fn main() {
             _ => unreachable!(), // clap ensures 0..=2 args
         };
 
-        let calendar = Calendar::new(current_year, mode);
+        let calendar = Calendar::new(CalendarConfig {
+            year: current_year,
+            mode,
+            week_start: args.week_start,
+            week_numbers: args.week_numbers,
+            per_row: args.columns as usize,
+            format: args.format,
+            events,
+        });
         calendar.display_months(current_month, months_before, months_after);
     } else {
         // Full year display mode - show year
         let year = args.year.unwrap_or_else(|| Local::now().year());
-        let calendar = Calendar::new(year, mode);
+        let calendar = Calendar::new(CalendarConfig {
+            year,
+            mode,
+            week_start: args.week_start,
+            week_numbers: args.week_numbers,
+            per_row: args.columns as usize,
+            format: args.format,
+            events,
+        });
         calendar.display();
     }
 }
\ No newline at end of file